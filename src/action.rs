@@ -0,0 +1,20 @@
+/// A message passed between input handling and component state.
+///
+/// Input handlers (key/mouse events) are translated into `Action`s instead of mutating state
+/// directly; components may also emit a follow-up `Action` from [`crate::component::Component::update`],
+/// which gets fed back through the same dispatch loop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Tick,
+    Quit,
+    FocusNext,
+    FocusPrev,
+    Focus,
+    Blur,
+    Increment,
+    Decrement,
+    Add,
+    Remove,
+    /// a transient, user-visible error, shown in the error banner
+    Error(String),
+}
@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+
+use ratatui::{
+    crossterm::event::{KeyEvent, MouseEvent},
+    layout::Rect,
+    Frame,
+};
+
+use crate::action::Action;
+
+/// A self-contained piece of UI state that can handle its own input and draw itself.
+///
+/// `App` owns a list of `Component`s and dispatches `Action`s to them instead of mutating their
+/// fields directly, so each component can be developed and tested independently of `App`.
+pub trait Component: Debug {
+    /// translates a key event into a follow-up action, if this component cares about it
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        let _ = key_event;
+        None
+    }
+
+    /// translates a mouse event into a follow-up action, if this component cares about it
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Option<Action> {
+        let _ = mouse_event;
+        None
+    }
+
+    /// applies an action to this component's state, optionally emitting a follow-up action
+    fn update(&mut self, action: Action) -> Option<Action>;
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect);
+}
@@ -0,0 +1,210 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind},
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Paragraph, Widget},
+    Frame,
+};
+
+use crate::{action::Action, component::Component};
+
+#[derive(Debug)]
+pub struct Counter {
+    focused: bool,
+    counter: u8,
+    min: u8,
+    max: u8,
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self {
+            focused: false,
+            counter: 0,
+            min: 0,
+            max: 2,
+        }
+    }
+}
+
+impl Counter {
+    pub fn start_focused() -> Self {
+        Self {
+            focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// increments the counter, clamped to `max`; returns `false` if it was already there
+    fn increment(&mut self) -> bool {
+        let next = self.counter.saturating_add(1).min(self.max);
+        let changed = next != self.counter;
+        self.counter = next;
+        changed
+    }
+
+    /// decrements the counter, clamped to `min`; returns `false` if it was already there
+    fn decrement(&mut self) -> bool {
+        let next = self.counter.saturating_sub(1).max(self.min);
+        let changed = next != self.counter;
+        self.counter = next;
+        changed
+    }
+}
+
+impl Component for Counter {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<Action> {
+        match key_event.code {
+            KeyCode::Char('j') => Some(Action::Decrement),
+            KeyCode::Char('k') => Some(Action::Increment),
+            _ => None,
+        }
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Option<Action> {
+        match mouse_event.kind {
+            MouseEventKind::ScrollUp => Some(Action::Increment),
+            MouseEventKind::ScrollDown => Some(Action::Decrement),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self, action: Action) -> Option<Action> {
+        match action {
+            Action::Increment if !self.increment() => Some(Action::Error(
+                "counter is already at its maximum value".into(),
+            )),
+            Action::Decrement if !self.decrement() => Some(Action::Error(
+                "counter is already at its minimum value".into(),
+            )),
+            Action::Focus => {
+                self.focused = true;
+                None
+            }
+            Action::Blur => {
+                self.focused = false;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(&*self, area);
+    }
+}
+
+impl Widget for &Counter {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Counter ".bold());
+        let border_style = match self.focused {
+            true => Style::default().blue(),
+            false => Style::default(),
+        };
+
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .style(border_style)
+            .border_set(border::THICK);
+
+        let counter_text = Text::from(vec![Line::from(vec![
+            "Value: ".into(),
+            self.counter.to_string().yellow(),
+        ])]);
+
+        Paragraph::new(counter_text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyModifiers;
+
+    use super::*;
+
+    // Frankly, I think this is dumb but keeping it as an example
+    #[test]
+    fn render() {
+        let counter = Counter::start_focused();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 50, 4));
+
+        (&counter).render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec![
+            "┏━━━━━━━━━━━━━━━━━━━ Counter ━━━━━━━━━━━━━━━━━━━━┓",
+            "┃                    Value: 0                    ┃",
+            "┃                                                ┃",
+            "┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛",
+        ]);
+        let title_style = Style::new().blue();
+        let title_text_style = Style::new().blue().bold();
+        let counter_style = Style::new().yellow();
+        expected.set_style(Rect::new(0, 0, 20, 1), title_style);
+        expected.set_style(Rect::new(20, 0, 9, 1), title_text_style);
+        expected.set_style(Rect::new(29, 0, 21, 1), title_style);
+        expected.set_style(Rect::new(0, 1, 28, 1), title_style);
+        expected.set_style(Rect::new(28, 1, 1, 1), counter_style);
+        expected.set_style(Rect::new(29, 1, 21, 1), title_style);
+        expected.set_style(Rect::new(0, 2, 50, 2), title_style);
+
+        // note ratatui also has an assert_buffer_eq! macro that can be used to
+        // compare buffers and display the differences in a more readable way
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn increment_and_decrement_update_counter() {
+        let mut counter = Counter::default();
+        assert_eq!(counter.update(Action::Increment), None);
+        assert_eq!(counter.counter, 1);
+
+        assert_eq!(counter.update(Action::Decrement), None);
+        assert_eq!(counter.counter, 0);
+    }
+
+    #[test]
+    fn clamps_at_bounds_and_emits_error() {
+        let mut counter = Counter::default();
+        assert_eq!(
+            counter.update(Action::Decrement),
+            Some(Action::Error(
+                "counter is already at its minimum value".into()
+            ))
+        );
+        assert_eq!(counter.counter, 0);
+
+        counter.update(Action::Increment);
+        counter.update(Action::Increment);
+        assert_eq!(
+            counter.update(Action::Increment),
+            Some(Action::Error(
+                "counter is already at its maximum value".into()
+            ))
+        );
+        assert_eq!(counter.counter, 2);
+    }
+
+    #[test]
+    fn handle_key_event_maps_to_actions() {
+        let mut counter = Counter::default();
+        let key_event = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(
+            counter.handle_key_event(key_event(KeyCode::Char('k'))),
+            Some(Action::Increment)
+        );
+        assert_eq!(
+            counter.handle_key_event(key_event(KeyCode::Char('j'))),
+            Some(Action::Decrement)
+        );
+        assert_eq!(
+            counter.handle_key_event(key_event(KeyCode::Char('q'))),
+            None
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use color_eyre::eyre::Result;
+use ratatui::crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
+
+const DEFAULT_TICK_RATE: Duration = Duration::from_millis(250);
+const DEFAULT_FRAME_RATE: Duration = Duration::from_millis(33);
+
+/// The unified event emitted by an [`EventSource`].
+///
+/// `Tick` and `Render` are synthetic events fired on a timer so the app can
+/// animate or poll independently of user input; `Key`, `Mouse` and `Resize`
+/// mirror crossterm's own event kinds, and `Error` carries the message of a
+/// failed poll or read.
+#[derive(Clone, Debug)]
+pub enum Event {
+    Tick,
+    Render,
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(u16, u16),
+    Error(String),
+}
+
+/// Reads terminal events on a background thread and forwards them, along
+/// with timer-driven `Tick`/`Render` events, over a channel.
+///
+/// Build one with [`EventSource::builder`] to customize the tick/frame rate,
+/// or [`EventSource::new`] for the defaults.
+pub struct EventSource {
+    receiver: mpsc::Receiver<Event>,
+    _worker: thread::JoinHandle<()>,
+}
+
+impl EventSource {
+    pub fn new() -> Self {
+        EventSourceBuilder::default().build()
+    }
+
+    pub fn builder() -> EventSourceBuilder {
+        EventSourceBuilder::default()
+    }
+
+    /// Blocks until the next event is available.
+    pub fn next(&self) -> Result<Event> {
+        Ok(self.receiver.recv()?)
+    }
+}
+
+impl Default for EventSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EventSourceBuilder {
+    tick_rate: Duration,
+    frame_rate: Duration,
+}
+
+impl Default for EventSourceBuilder {
+    fn default() -> Self {
+        Self {
+            tick_rate: DEFAULT_TICK_RATE,
+            frame_rate: DEFAULT_FRAME_RATE,
+        }
+    }
+}
+
+impl EventSourceBuilder {
+    pub fn tick_rate(mut self, tick_rate: Duration) -> Self {
+        self.tick_rate = tick_rate;
+        self
+    }
+
+    pub fn frame_rate(mut self, frame_rate: Duration) -> Self {
+        self.frame_rate = frame_rate;
+        self
+    }
+
+    pub fn build(self) -> EventSource {
+        let (sender, receiver) = mpsc::channel();
+        let tick_rate = self.tick_rate;
+        let frame_rate = self.frame_rate;
+        let worker = thread::spawn(move || Self::run(&sender, tick_rate, frame_rate));
+        EventSource {
+            receiver,
+            _worker: worker,
+        }
+    }
+
+    fn run(sender: &mpsc::Sender<Event>, tick_rate: Duration, frame_rate: Duration) {
+        let mut last_tick = Instant::now();
+        let mut last_render = Instant::now();
+        loop {
+            let timeout = time_until(last_tick, tick_rate).min(time_until(last_render, frame_rate));
+
+            match event::poll(timeout) {
+                Ok(true) => match event::read() {
+                    Ok(CrosstermEvent::Key(key)) => {
+                        if sender.send(Event::Key(key)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(CrosstermEvent::Mouse(mouse)) => {
+                        if sender.send(Event::Mouse(mouse)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(CrosstermEvent::Resize(width, height)) => {
+                        if sender.send(Event::Resize(width, height)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        if sender.send(Event::Error(err.to_string())).is_err() {
+                            return;
+                        }
+                    }
+                },
+                Ok(false) => {}
+                Err(err) => {
+                    if sender.send(Event::Error(err.to_string())).is_err() {
+                        return;
+                    }
+                }
+            }
+
+            if last_tick.elapsed() >= tick_rate {
+                if sender.send(Event::Tick).is_err() {
+                    return;
+                }
+                last_tick = Instant::now();
+            }
+            if last_render.elapsed() >= frame_rate {
+                if sender.send(Event::Render).is_err() {
+                    return;
+                }
+                last_render = Instant::now();
+            }
+        }
+    }
+}
+
+/// Time remaining until `rate` has elapsed since `since`, `ZERO` if it's
+/// already due.
+fn time_until(since: Instant, rate: Duration) -> Duration {
+    rate.saturating_sub(since.elapsed())
+}
@@ -1,83 +1,160 @@
-use color_eyre::eyre::{bail, WrapErr};
+use std::sync::mpsc;
+
+use color_eyre::eyre::{eyre, WrapErr};
 use ratatui::{
-    buffer::Buffer,
-    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Style, Stylize},
-    symbols::border,
-    text::{Line, Span, Text},
+    text::{Line, Span},
     widgets::{
         block::{Position, Title},
-        Block, Borders, Paragraph, Widget,
+        Block, Borders, Paragraph,
     },
     Frame,
 };
 
+mod action;
+mod component;
+mod counter;
+mod event;
 mod tui;
 
+use action::Action;
+use component::Component;
+use counter::Counter;
+use event::{Event, EventSource};
+
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
-    let mut terminal = tui::init()?;
-    let app_result = App::default().run(&mut terminal);
-    if let Err(err) = tui::restore() {
-        eprintln!(
-            "failed to restore terminal. Run `reset` or restart your terminal to recover: {}",
-            err
-        );
-    }
+    let mut terminal = tui::init();
+    let events = EventSource::new();
+    let app_result = App::default().run(&mut terminal, &events);
+    tui::restore();
     app_result
 }
 
 #[derive(Debug)]
 pub struct App {
     focus_on: usize,
-    counters: Vec<Counter>,
+    components: Vec<Box<dyn Component>>,
     exit: bool,
+    /// the horizontal rect each component was last rendered into, used to hit-test mouse events
+    counter_layout: Vec<Rect>,
+    /// a transient message shown in the error banner, cleared on the next successful keypress
+    error: Option<String>,
+    action_tx: mpsc::Sender<Action>,
+    action_rx: mpsc::Receiver<Action>,
 }
 
 impl Default for App {
     fn default() -> Self {
+        let (action_tx, action_rx) = mpsc::channel();
         Self {
             focus_on: 0,
-            counters: vec![
-                Counter::start_focused(),
-                Counter::default(),
-                Counter::default(),
+            components: vec![
+                Box::new(Counter::start_focused()),
+                Box::new(Counter::default()),
+                Box::new(Counter::default()),
             ],
             exit: false,
-        }
-    }
-}
-
-#[derive(Debug, Default)]
-pub struct Counter {
-    focused: bool,
-    counter: u8,
-}
-
-impl Counter {
-    fn start_focused() -> Self {
-        Self {
-            focused: true,
-            counter: 0,
+            counter_layout: Vec::new(),
+            error: None,
+            action_tx,
+            action_rx,
         }
     }
 }
 
 impl App {
     /// runs the application's main loop until the user quits
-    pub fn run(&mut self, terminal: &mut tui::Tui) -> color_eyre::Result<()> {
+    pub fn run(
+        &mut self,
+        terminal: &mut tui::DefaultTerminal,
+        events: &EventSource,
+    ) -> color_eyre::Result<()> {
         while !self.exit {
-            terminal.draw(|frame| self.render_frame(frame))?;
-            self.handle_events().wrap_err("handle events failed")?;
+            match events.next()? {
+                Event::Tick => self.dispatch(Action::Tick, self.focus_on)?,
+                Event::Render => {
+                    terminal.draw(|frame| self.render_frame(frame))?;
+                }
+                Event::Key(key_event) => self
+                    .handle_key_event(key_event)
+                    .wrap_err_with(|| format!("handling key event failed:\n{key_event:#?}"))?,
+                Event::Mouse(mouse_event) => self
+                    .handle_mouse_event(mouse_event)
+                    .wrap_err_with(|| format!("handling mouse event failed:\n{mouse_event:#?}"))?,
+                Event::Resize(_, _) => {}
+                Event::Error(err) => return Err(eyre!(err)),
+            }
+        }
+        Ok(())
+    }
+
+    /// sends `action` to the focused/targeted component, draining any follow-up actions it (or
+    /// anything downstream) emits before returning
+    fn dispatch(&mut self, action: Action, target: usize) -> color_eyre::Result<()> {
+        self.action_tx.send(action)?;
+        while let Ok(action) = self.action_rx.try_recv() {
+            if let Some(follow_up) = self.apply(action, target)? {
+                self.action_tx.send(follow_up)?;
+            }
         }
         Ok(())
     }
 
-    fn render_frame(&self, frame: &mut Frame) {
+    /// applies a single action, returning a follow-up action for the dispatch loop to feed back in
+    fn apply(&mut self, action: Action, target: usize) -> color_eyre::Result<Option<Action>> {
+        match action {
+            Action::Quit => {
+                self.exit();
+                Ok(None)
+            }
+            Action::FocusNext => {
+                self.next_counter()?;
+                Ok(None)
+            }
+            Action::FocusPrev => {
+                self.previous_counter()?;
+                Ok(None)
+            }
+            Action::Add => {
+                self.add_counter()?;
+                Ok(None)
+            }
+            Action::Remove => {
+                self.remove_focused_counter()?;
+                Ok(None)
+            }
+            Action::Error(message) => {
+                self.error = Some(message);
+                Ok(None)
+            }
+            Action::Tick => {
+                // dispatch each component's own follow-up back to itself, rather than dropping
+                // it, so e.g. a future Tick-driven Error still reaches the error banner
+                for index in 0..self.components.len() {
+                    if let Some(follow_up) = self.components[index].update(Action::Tick) {
+                        self.dispatch(follow_up, index)?;
+                    }
+                }
+                Ok(None)
+            }
+            Action::Focus | Action::Blur | Action::Increment | Action::Decrement => Ok(self
+                .components
+                .get_mut(target)
+                .and_then(|component| component.update(action))),
+        }
+    }
+
+    fn render_frame(&mut self, frame: &mut Frame) {
         let outer_layout = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(vec![Constraint::Percentage(5), Constraint::Percentage(99)])
+            .constraints(vec![
+                Constraint::Percentage(5),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ])
             .split(frame.area());
 
         let title = Title::from(Line::from("Multi-Counter").bold().blue().on_white());
@@ -96,6 +173,10 @@ impl App {
             Span::styled("<h>", Style::new().blue().bold()),
             " Right ".into(),
             Span::styled("<l>", Style::new().blue().bold()),
+            " Add ".into(),
+            Span::styled("<a>", Style::new().blue().bold()),
+            " Remove ".into(),
+            Span::styled("<x>", Style::new().blue().bold()),
             " Quit ".into(),
             Span::styled("<Q> ", Style::new().blue().bold()),
         ]))
@@ -104,39 +185,52 @@ impl App {
 
         frame.render_widget(instructions, outer_layout[0]);
 
-        let counter_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(vec![
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-            ])
-            .split(outer_layout[1]);
+        if let Some(error) = &self.error {
+            let banner = Paragraph::new(Line::from(error.as_str().white().bold()))
+                .alignment(Alignment::Center)
+                .style(Style::default().on_red());
+            frame.render_widget(banner, outer_layout[1]);
+        }
 
-        for (i, rect) in counter_layout.iter().enumerate() {
-            frame.render_widget(&self.counters[i], *rect);
+        if self.components.is_empty() {
+            self.counter_layout = Vec::new();
+            return;
         }
-    }
 
-    fn handle_events(&mut self) -> color_eyre::Result<()> {
-        match event::read()? {
-            // it's important to check that the event is a key press event as
-            // crossterm also emits key release and repeat events on Windows.
-            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => self
-                .handle_key_event(key_event)
-                .wrap_err_with(|| format!("handling key event failed:\n{key_event:#?}")),
-            _ => Ok(()),
+        let count = self.components.len() as u32;
+        let constraints = vec![Constraint::Ratio(1, count); self.components.len()];
+        let counter_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(outer_layout[2]);
+
+        for (rect, component) in counter_layout.iter().zip(self.components.iter_mut()) {
+            component.draw(frame, *rect);
         }
+        self.counter_layout = counter_layout.to_vec();
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) -> color_eyre::Result<()> {
-        match key_event.code {
-            KeyCode::Char('q') => self.exit(),
-            KeyCode::Char('l') => self.next_counter()?,
-            KeyCode::Char('h') => self.previous_counter()?,
-            KeyCode::Char('j') => self.decrement_current_counter()?,
-            KeyCode::Char('k') => self.increment_current_counter()?,
-            _ => {}
+        // it's important to check that the event is a key press event as
+        // crossterm also emits key release and repeat events on Windows.
+        if key_event.kind != KeyEventKind::Press {
+            return Ok(());
+        }
+        let action = match key_event.code {
+            KeyCode::Char('q') => Some(Action::Quit),
+            KeyCode::Char('l') => Some(Action::FocusNext),
+            KeyCode::Char('h') => Some(Action::FocusPrev),
+            KeyCode::Char('a') => Some(Action::Add),
+            KeyCode::Char('x') => Some(Action::Remove),
+            _ => self
+                .components
+                .get_mut(self.focus_on)
+                .and_then(|component| component.handle_key_event(key_event)),
+        };
+        if let Some(action) = action {
+            // only clear on a keypress that actually maps to something, not every keystroke
+            self.error = None;
+            self.dispatch(action, self.focus_on)?;
         }
         Ok(())
     }
@@ -145,138 +239,257 @@ impl App {
         self.exit = true;
     }
 
+    /// moves focus to `index`, routing the blur/focus through `dispatch` like any other action so
+    /// a component's follow-up (e.g. an error) is fed back instead of dropped
+    fn set_focus(&mut self, index: usize) -> color_eyre::Result<()> {
+        if self.focus_on != index {
+            self.dispatch(Action::Blur, self.focus_on)?;
+        }
+        self.focus_on = index;
+        self.dispatch(Action::Focus, index)
+    }
+
     fn next_counter(&mut self) -> color_eyre::Result<()> {
-        self.counters[self.focus_on].focused = false;
-        if self.focus_on == self.counters.len() - 1 {
-            self.focus_on = 0;
-        } else {
-            self.focus_on = self.focus_on + 1;
+        if self.components.is_empty() {
+            return Ok(());
         }
-        self.counters[self.focus_on].focused = true;
-        Ok(())
+        let next = if self.focus_on == self.components.len() - 1 {
+            0
+        } else {
+            self.focus_on + 1
+        };
+        self.set_focus(next)
     }
 
     fn previous_counter(&mut self) -> color_eyre::Result<()> {
-        self.counters[self.focus_on].focused = false;
-        if self.focus_on == 0 {
-            self.focus_on = self.counters.len() - 1;
-        } else {
-            self.focus_on = self.focus_on - 1;
+        if self.components.is_empty() {
+            return Ok(());
         }
-        self.counters[self.focus_on].focused = true;
-        Ok(())
+        let previous = if self.focus_on == 0 {
+            self.components.len() - 1
+        } else {
+            self.focus_on - 1
+        };
+        self.set_focus(previous)
     }
 
-    fn increment_current_counter(&mut self) -> color_eyre::Result<()> {
-        let curr = &mut self.counters[self.focus_on];
-        curr.counter += 1;
-        if curr.counter > 2 {
-            bail!("counter overflow");
+    fn add_counter(&mut self) -> color_eyre::Result<()> {
+        self.components.push(Box::new(Counter::default()));
+        // growing from empty: the pushed counter is the only one and should receive input focus
+        if self.components.len() == 1 {
+            self.focus_on = 0;
+            return self.dispatch(Action::Focus, 0);
         }
         Ok(())
     }
 
-    fn decrement_current_counter(&mut self) -> color_eyre::Result<()> {
-        let curr = &mut self.counters[self.focus_on];
-        curr.counter -= 1;
-        Ok(())
+    fn remove_focused_counter(&mut self) -> color_eyre::Result<()> {
+        if self.components.is_empty() {
+            return Ok(());
+        }
+        self.components.remove(self.focus_on);
+        if self.components.is_empty() {
+            self.focus_on = 0;
+            return Ok(());
+        }
+        self.focus_on = self.focus_on.min(self.components.len() - 1);
+        self.dispatch(Action::Focus, self.focus_on)
     }
-}
 
-impl Widget for &Counter {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let title = Title::from(" Counter ".bold());
-        let border_style = match self.focused {
-            true => Style::default().blue(),
-            false => Style::default(),
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> color_eyre::Result<()> {
+        let Some(index) = self.counter_at(mouse_event.column, mouse_event.row) else {
+            return Ok(());
         };
+        // counter_layout is only refreshed on the next render, so a removal can leave it
+        // pointing past the current component count; ignore hits against such a stale rect
+        if index >= self.components.len() {
+            return Ok(());
+        }
+        if let MouseEventKind::Down(MouseButton::Left) = mouse_event.kind {
+            return self.set_focus(index);
+        }
+        if let Some(action) = self
+            .components
+            .get_mut(index)
+            .and_then(|component| component.handle_mouse_event(mouse_event))
+        {
+            self.dispatch(action, index)?;
+        }
+        Ok(())
+    }
 
-        let block = Block::bordered()
-            .title(title.alignment(Alignment::Center))
-            .style(border_style)
-            .border_set(border::THICK);
-
-        let counter_text = Text::from(vec![Line::from(vec![
-            "Value: ".into(),
-            self.counter.to_string().yellow(),
-        ])]);
-
-        Paragraph::new(counter_text)
-            .centered()
-            .block(block)
-            .render(area, buf);
+    /// returns the index of the component whose last-rendered rect contains `(column, row)`
+    fn counter_at(&self, column: u16, row: u16) -> Option<usize> {
+        let position = ratatui::layout::Position::new(column, row);
+        self.counter_layout
+            .iter()
+            .position(|rect| rect.contains(position))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use ratatui::style::Style;
+    use ratatui::{backend::TestBackend, crossterm::event::KeyModifiers, Terminal};
 
     use super::*;
 
-    // Frankly, I think this is dumb but keeping it as an example
-    #[test]
-    fn render() {
-        let app = App::default();
-        let mut buf = Buffer::empty(Rect::new(0, 0, 50, 4));
-
-        app.counters[0].render(buf.area, &mut buf);
-
-        let mut expected = Buffer::with_lines(vec![
-            "┏━━━━━━━━━━━━━━━━━━━ Counter ━━━━━━━━━━━━━━━━━━━━┓",
-            "┃                    Value: 0                    ┃",
-            "┃                                                ┃",
-            "┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛",
-        ]);
-        let title_style = Style::new().blue();
-        let title_text_style = Style::new().blue().bold();
-        let counter_style = Style::new().yellow();
-        expected.set_style(Rect::new(0, 0, 20, 1), title_style);
-        expected.set_style(Rect::new(20, 0, 9, 1), title_text_style);
-        expected.set_style(Rect::new(29, 0, 21, 1), title_style);
-        expected.set_style(Rect::new(0, 1, 28, 1), title_style);
-        expected.set_style(Rect::new(28, 1, 1, 1), counter_style);
-        expected.set_style(Rect::new(29, 1, 21, 1), title_style);
-        expected.set_style(Rect::new(0, 2, 50, 2), title_style);
-
-        // note ratatui also has an assert_buffer_eq! macro that can be used to
-        // compare buffers and display the differences in a more readable way
-        assert_eq!(buf, expected);
+    /// draws `app` into a `width x height` test terminal and returns its rows as strings, so
+    /// tests can assert on rendered content without reaching into component internals
+    fn render_lines(app: &mut App, width: u16, height: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| app.render_frame(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| (0..width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
     }
 
     #[test]
-    fn handle_key_event() {
+    fn handle_key_event_increment_and_decrement() {
         let mut app = App::default();
         app.handle_key_event(KeyCode::Char('k').into()).unwrap();
-        assert_eq!(app.counters[0].counter, 1);
+        let lines = render_lines(&mut app, 60, 10).join("");
+        assert_eq!(lines.matches("Value: 1").count(), 1);
 
         app.handle_key_event(KeyCode::Char('j').into()).unwrap();
-        assert_eq!(app.counters[0].counter, 0);
+        let lines = render_lines(&mut app, 60, 10).join("");
+        assert_eq!(lines.matches("Value: 0").count(), 3);
+    }
 
+    #[test]
+    fn handle_key_event_quit() {
         let mut app = App::default();
         app.handle_key_event(KeyCode::Char('q').into()).unwrap();
         assert!(app.exit);
     }
 
     #[test]
-    #[should_panic(expected = "attempt to subtract with overflow")]
-    fn handle_key_event_panic() {
+    fn add_and_remove_component() {
+        let mut app = App::default();
+        assert_eq!(app.components.len(), 3);
+
+        app.handle_key_event(KeyCode::Char('a').into()).unwrap();
+        assert_eq!(app.components.len(), 4);
+
+        app.focus_on = 3;
+        app.handle_key_event(KeyCode::Char('x').into()).unwrap();
+        assert_eq!(app.components.len(), 3);
+        // removing the last component clamps focus back onto the new last component
+        assert_eq!(app.focus_on, 2);
+    }
+
+    #[test]
+    fn render_scales_layout_to_counter_count() {
+        let mut app = App {
+            components: vec![
+                Box::new(Counter::start_focused()),
+                Box::new(Counter::default()),
+                Box::new(Counter::default()),
+                Box::new(Counter::default()),
+            ],
+            ..App::default()
+        };
+        let lines = render_lines(&mut app, 80, 10).join("");
+        assert_eq!(lines.matches("Value: 0").count(), 4);
+        assert_eq!(app.counter_layout.len(), 4);
+    }
+
+    #[test]
+    fn remove_all_components_leaves_app_usable() {
+        let mut app = App::default();
+        for _ in 0..3 {
+            app.handle_key_event(KeyCode::Char('x').into()).unwrap();
+        }
+        assert!(app.components.is_empty());
+        assert_eq!(app.focus_on, 0);
+
+        // further key events and a render should be no-ops, not panics
+        app.handle_key_event(KeyCode::Char('k').into()).unwrap();
+        render_lines(&mut app, 50, 10);
+    }
+
+    #[test]
+    fn add_counter_from_empty_focuses_new_counter() {
+        let mut app = App::default();
+        for _ in 0..3 {
+            app.handle_key_event(KeyCode::Char('x').into()).unwrap();
+        }
+        assert!(app.components.is_empty());
+
+        app.handle_key_event(KeyCode::Char('a').into()).unwrap();
+        assert_eq!(app.focus_on, 0);
+        app.handle_key_event(KeyCode::Char('k').into()).unwrap();
+        let lines = render_lines(&mut app, 60, 10).join("");
+        assert_eq!(lines.matches("Value: 1").count(), 1);
+    }
+
+    #[test]
+    fn handle_mouse_event_scroll_and_click() {
+        let mut app = App {
+            counter_layout: vec![
+                Rect::new(0, 0, 10, 10),
+                Rect::new(10, 0, 10, 10),
+                Rect::new(20, 0, 10, 10),
+            ],
+            ..App::default()
+        };
+
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::ScrollUp,
+            column: 15,
+            row: 5,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+
+        // rendering recomputes counter_layout for the real terminal size, so check the click
+        // against the still-manual layout before drawing anything
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 25,
+            row: 5,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+        assert_eq!(app.focus_on, 2);
+
+        let lines = render_lines(&mut app, 60, 10).join("");
+        assert_eq!(lines.matches("Value: 1").count(), 1);
+    }
+
+    #[test]
+    fn decrement_below_min_sets_error_banner() {
         let mut app = App::default();
-        let _ = app.handle_key_event(KeyCode::Char('j').into());
+        app.handle_key_event(KeyCode::Char('j').into()).unwrap();
+        assert_eq!(
+            app.error.as_deref(),
+            Some("counter is already at its minimum value")
+        );
+        let lines = render_lines(&mut app, 60, 10).join("");
+        assert!(lines.contains("counter is already at its minimum value"));
     }
 
     #[test]
-    fn handle_key_event_overflow() {
+    fn increment_above_max_sets_error_banner() {
         let mut app = App::default();
-        println!("{:?}", app);
-        assert!(app.handle_key_event(KeyCode::Char('k').into()).is_ok());
-        println!("{:?}", app);
-        assert!(app.handle_key_event(KeyCode::Char('k').into()).is_ok());
+        app.handle_key_event(KeyCode::Char('k').into()).unwrap();
+        app.handle_key_event(KeyCode::Char('k').into()).unwrap();
+        assert!(app.error.is_none());
+
+        app.handle_key_event(KeyCode::Char('k').into()).unwrap();
         assert_eq!(
-            app.handle_key_event(KeyCode::Char('k').into())
-                .unwrap_err()
-                .to_string(),
-            "counter overflow"
+            app.error.as_deref(),
+            Some("counter is already at its maximum value")
         );
     }
+
+    #[test]
+    fn error_banner_clears_on_next_successful_keypress() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Char('j').into()).unwrap();
+        assert!(app.error.is_some());
+
+        app.handle_key_event(KeyCode::Char('k').into()).unwrap();
+        assert!(app.error.is_none());
+    }
 }
@@ -0,0 +1,51 @@
+use std::io::{stdout, Stdout};
+
+use color_eyre::eyre::Result;
+use ratatui::crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::prelude::*;
+
+/// The terminal type used throughout this application.
+pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// Initializes the terminal, panicking if it can't be set up. Use [`try_init`] if you'd rather
+/// handle the error yourself.
+pub fn init() -> DefaultTerminal {
+    try_init().expect("failed to initialize the terminal")
+}
+
+/// Initializes the terminal: installs the panic hook, enables raw mode, enters the alternate
+/// screen and enables mouse capture.
+pub fn try_init() -> Result<DefaultTerminal> {
+    install_panic_hook();
+    execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    enable_raw_mode()?;
+    Ok(Terminal::new(CrosstermBackend::new(stdout()))?)
+}
+
+/// Restores the terminal, panicking if it can't be restored. Use [`try_restore`] if you'd rather
+/// handle the error yourself.
+pub fn restore() {
+    try_restore().expect("failed to restore the terminal");
+}
+
+/// Restores the terminal to its original state.
+pub fn try_restore() -> Result<()> {
+    execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    disable_raw_mode()?;
+    Ok(())
+}
+
+/// Replaces the panic hook with one that restores the terminal before delegating to the previous
+/// hook (color_eyre's, if installed), so a panic mid-render doesn't leave the terminal in raw
+/// mode / the alternate screen.
+fn install_panic_hook() {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = try_restore();
+        original_hook(panic_info);
+    }));
+}